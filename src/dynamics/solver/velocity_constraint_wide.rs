@@ -1,5 +1,5 @@
 use super::{AnyVelocityConstraint, DeltaVel};
-use crate::dynamics::{IntegrationParameters, RigidBodySet};
+use crate::dynamics::{IntegrationParameters, RigidBodyHandle, RigidBodySet};
 use crate::geometry::{ContactManifold, ContactManifoldIndex};
 use crate::math::{
     AngVector, AngularInertia, Isometry, Point, SimdFloat, Vector, DIM, MAX_MANIFOLD_POINTS,
@@ -9,6 +9,21 @@ use crate::utils::{WAngularInertia, WBasis, WCross, WDot};
 use num::Zero;
 use simba::simd::{SimdPartialOrd, SimdValue};
 
+// A converged per-contact reaction, de-SIMD'd to plain f32/nalgebra types.
+pub struct SolvedContact {
+    pub body1: RigidBodyHandle,
+    pub body2: RigidBodyHandle,
+    pub contact_point: Point<f32>,
+    pub normal_impulse: f32,
+    pub tangent_impulse: [f32; DIM - 1],
+    pub total_impulse: Vector<f32>,
+}
+
+// Bullet-style "postSolveContacts" hook, fired once per contact from `writeback_impulses`.
+pub trait ContactSolverCallback {
+    fn solved_contact(&self, contact: &SolvedContact);
+}
+
 #[derive(Copy, Clone, Debug)]
 pub(crate) struct WVelocityConstraintElementPart {
     pub gcross1: AngVector<SimdFloat>,
@@ -16,6 +31,10 @@ pub(crate) struct WVelocityConstraintElementPart {
     pub rhs: SimdFloat,
     pub impulse: SimdFloat,
     pub r: SimdFloat,
+    // Split-impulse pseudo-velocity bias/impulse (normal part only), solved against
+    // `mj_lambdas_push` instead of `mj_lambdas`.
+    pub rhs_push: SimdFloat,
+    pub impulse_push: SimdFloat,
 }
 
 impl WVelocityConstraintElementPart {
@@ -26,6 +45,8 @@ impl WVelocityConstraintElementPart {
             rhs: SimdFloat::zero(),
             impulse: SimdFloat::zero(),
             r: SimdFloat::zero(),
+            rhs_push: SimdFloat::zero(),
+            impulse_push: SimdFloat::zero(),
         }
     }
 }
@@ -34,6 +55,11 @@ impl WVelocityConstraintElementPart {
 pub(crate) struct WVelocityConstraintElement {
     pub normal_part: WVelocityConstraintElementPart,
     pub tangent_parts: [WVelocityConstraintElementPart; DIM - 1],
+    // Angular-only rows resisting spin/rolling; touch `mj_lambda*.angular` only.
+    pub spinning_part: WVelocityConstraintElementPart,
+    pub rolling_parts: [WVelocityConstraintElementPart; DIM - 1],
+    // World-space contact point on the first collider, for `ContactSolverCallback`.
+    pub point: Point<SimdFloat>,
 }
 
 impl WVelocityConstraintElement {
@@ -41,6 +67,9 @@ impl WVelocityConstraintElement {
         Self {
             normal_part: WVelocityConstraintElementPart::zero(),
             tangent_parts: [WVelocityConstraintElementPart::zero(); DIM - 1],
+            spinning_part: WVelocityConstraintElementPart::zero(),
+            rolling_parts: [WVelocityConstraintElementPart::zero(); DIM - 1],
+            point: Point::origin(),
         }
     }
 }
@@ -53,18 +82,88 @@ pub(crate) struct WVelocityConstraint {
     pub im1: SimdFloat,
     pub im2: SimdFloat,
     pub limit: SimdFloat,
+    pub spinning_limit: SimdFloat,
+    pub rolling_limit: SimdFloat,
+    // Whether `solve` also runs the split-impulse position-correction pass.
+    pub use_split_impulse: bool,
+    // Per-body relaxation factor for `solve_jacobi` (unused by the Gauss-Seidel `solve`).
+    pub inv_lambda1_count: SimdFloat,
+    pub inv_lambda2_count: SimdFloat,
     pub mj_lambda1: [usize; SIMD_WIDTH],
     pub mj_lambda2: [usize; SIMD_WIDTH],
     pub manifold_id: [ContactManifoldIndex; SIMD_WIDTH],
     pub manifold_contact_id: usize,
+    // Real (non-padding) lane count; padding lanes repeat the last manifold and must not
+    // double-fire the post-solve callback.
+    pub num_active_lanes: u8,
+}
+
+// Shared accumulated-impulse updates for `solve`/`solve_jacobi`, so the clamp logic lives in
+// one place: `solve_unilateral` for the normal and split-impulse rows, `solve_bilateral` for
+// the 2D tangent friction and the rolling/spinning rows.
+#[inline]
+fn solve_unilateral(
+    impulse: SimdFloat,
+    r: SimdFloat,
+    dimpulse: SimdFloat,
+) -> (SimdFloat, SimdFloat) {
+    let new_impulse = (impulse - r * dimpulse).simd_max(SimdFloat::zero());
+    (new_impulse, new_impulse - impulse)
+}
+
+#[inline]
+fn solve_bilateral(
+    impulse: SimdFloat,
+    r: SimdFloat,
+    dimpulse: SimdFloat,
+    limit: SimdFloat,
+) -> (SimdFloat, SimdFloat) {
+    let new_impulse = (impulse - r * dimpulse).simd_clamp(-limit, limit);
+    (new_impulse, new_impulse - impulse)
+}
+
+// Couples two tangent rows into a single 2D impulse vector clamped to a disc (circular
+// friction cone) instead of clamping each row to a box. Used by the 3D tangent friction.
+#[inline]
+fn solve_cone(
+    impulse0: SimdFloat,
+    impulse1: SimdFloat,
+    r0: SimdFloat,
+    r1: SimdFloat,
+    dimpulse0: SimdFloat,
+    dimpulse1: SimdFloat,
+    limit: SimdFloat,
+) -> (SimdFloat, SimdFloat, SimdFloat, SimdFloat) {
+    let candidate0 = impulse0 - r0 * dimpulse0;
+    let candidate1 = impulse1 - r1 * dimpulse1;
+
+    let norm = (candidate0 * candidate0 + candidate1 * candidate1).simd_sqrt();
+    let scale = (limit / norm).simd_min(SimdFloat::splat(1.0));
+    let scale = scale.select(norm.simd_gt(SimdFloat::zero()), SimdFloat::splat(1.0));
+
+    let new_impulse0 = candidate0 * scale;
+    let new_impulse1 = candidate1 * scale;
+    (
+        new_impulse0,
+        new_impulse1,
+        new_impulse0 - impulse0,
+        new_impulse1 - impulse1,
+    )
 }
 
 impl WVelocityConstraint {
+    // FIXME: num_constraints_per_body/num_active_lanes/use_split_impulse and the
+    // ContactManifold/ContactManifoldPoint fields they read (spinning_friction,
+    // rolling_friction, spinning_impulse, rolling_impulse) aren't produced by any caller or
+    // by IntegrationParameters yet; nothing here is reachable until the island solver and
+    // manifold types grow them.
     pub fn generate(
         params: &IntegrationParameters,
         manifold_id: [ContactManifoldIndex; SIMD_WIDTH],
         manifolds: [&ContactManifold; SIMD_WIDTH],
         bodies: &RigidBodySet,
+        num_constraints_per_body: &[u32],
+        num_active_lanes: usize,
         out_constraints: &mut Vec<AnyVelocityConstraint>,
         push: bool,
     ) {
@@ -103,7 +202,19 @@ impl WVelocityConstraint {
         let mj_lambda1 = array![|ii| rbs1[ii].active_set_offset; SIMD_WIDTH];
         let mj_lambda2 = array![|ii| rbs2[ii].active_set_offset; SIMD_WIDTH];
 
+        // Diagonal under-relaxation for `solve_jacobi` (1 / constraints touching the body).
+        let inv_lambda1_count = SimdFloat::from(
+            array![|ii| 1.0 / num_constraints_per_body[mj_lambda1[ii]].max(1) as f32; SIMD_WIDTH],
+        );
+        let inv_lambda2_count = SimdFloat::from(
+            array![|ii| 1.0 / num_constraints_per_body[mj_lambda2[ii]].max(1) as f32; SIMD_WIDTH],
+        );
+
         let friction = SimdFloat::from(array![|ii| manifolds[ii].friction; SIMD_WIDTH]);
+        let spinning_friction =
+            SimdFloat::from(array![|ii| manifolds[ii].spinning_friction; SIMD_WIDTH]);
+        let rolling_friction =
+            SimdFloat::from(array![|ii| manifolds[ii].rolling_friction; SIMD_WIDTH]);
         let restitution = SimdFloat::from(array![|ii| manifolds[ii].restitution; SIMD_WIDTH]);
         let restitution_velocity_threshold =
             SimdFloat::splat(params.restitution_velocity_threshold);
@@ -122,11 +233,17 @@ impl WVelocityConstraint {
                 im1,
                 im2,
                 limit: friction,
+                spinning_limit: spinning_friction,
+                rolling_limit: rolling_friction,
+                use_split_impulse: params.use_split_impulse,
+                inv_lambda1_count,
+                inv_lambda2_count,
                 mj_lambda1,
                 mj_lambda2,
                 manifold_id,
                 manifold_contact_id: l,
                 num_contacts: num_points as u8,
+                num_active_lanes: num_active_lanes as u8,
             };
 
             for k in 0..num_points {
@@ -142,6 +259,8 @@ impl WVelocityConstraint {
                 let impulse =
                     SimdFloat::from(array![|ii| manifold_points[ii][k].impulse; SIMD_WIDTH]);
 
+                constraint.elements[k].point = p1;
+
                 let dp1 = p1 - world_com1;
                 let dp2 = p2 - world_com2;
 
@@ -159,13 +278,24 @@ impl WVelocityConstraint {
                     let use_restitution = rhs.simd_le(-restitution_velocity_threshold);
                     let rhs_with_restitution = rhs + rhs * restitution;
                     rhs = rhs_with_restitution.select(use_restitution, rhs);
+
+                    // Speculative-contact margin: stays on the real bias regardless of split impulse.
                     rhs += dist.simd_max(SimdFloat::zero()) * inv_dt;
 
+                    // Split-impulse: penetration recovery as a separate pseudo-velocity.
+                    let rhs_push = if params.use_split_impulse {
+                        params.erp * dist.simd_min(SimdFloat::zero()) * inv_dt
+                    } else {
+                        SimdFloat::zero()
+                    };
+
                     constraint.elements[k].normal_part = WVelocityConstraintElementPart {
                         gcross1,
                         gcross2,
                         rhs,
+                        rhs_push,
                         impulse: impulse * warmstart_coeff,
+                        impulse_push: SimdFloat::zero(),
                         r,
                     };
                 }
@@ -193,7 +323,78 @@ impl WVelocityConstraint {
                         gcross1,
                         gcross2,
                         rhs,
+                        rhs_push: SimdFloat::zero(),
                         impulse: impulse * warmstart_coeff,
+                        impulse_push: SimdFloat::zero(),
+                        r,
+                    };
+                }
+
+                // Spinning friction: resists relative angular velocity about the normal.
+                // Only meaningful in 3D; 2D has a single angular DOF already orthogonal to
+                // the in-plane normal, so there's no separate "spin about the normal" to resist.
+                #[cfg(feature = "dim2")]
+                {
+                    constraint.elements[k].spinning_part = WVelocityConstraintElementPart::zero();
+                }
+                #[cfg(feature = "dim3")]
+                {
+                    let spinning_impulse = SimdFloat::from(
+                        array![|ii| manifold_points[ii][k].spinning_impulse; SIMD_WIDTH],
+                    );
+
+                    let gcross1 = ii1.transform_vector(force_dir1);
+                    let gcross2 = ii2.transform_vector(-force_dir1);
+                    let r = SimdFloat::splat(1.0) / (gcross1.gdot(gcross1) + gcross2.gdot(gcross2));
+                    let rhs = (angvel1 - angvel2).gdot(force_dir1);
+
+                    constraint.elements[k].spinning_part = WVelocityConstraintElementPart {
+                        gcross1,
+                        gcross2,
+                        rhs,
+                        rhs_push: SimdFloat::zero(),
+                        impulse: spinning_impulse * warmstart_coeff,
+                        impulse_push: SimdFloat::zero(),
+                        r,
+                    };
+                }
+
+                // Rolling friction: resists relative angular velocity across the tangent plane.
+                // In 2D the single tangent-plane row is just the scalar angular DOF itself, so
+                // it uses the unprojected angvel1 - angvel2 rather than a per-tangent .gdot.
+                for j in 0..DIM - 1 {
+                    #[cfg(feature = "dim2")]
+                    let rolling_impulse = SimdFloat::from(
+                        array![|ii| manifold_points[ii][k].rolling_impulse; SIMD_WIDTH],
+                    );
+                    #[cfg(feature = "dim3")]
+                    let rolling_impulse = SimdFloat::from(
+                        array![|ii| manifold_points[ii][k].rolling_impulse[j]; SIMD_WIDTH],
+                    );
+
+                    #[cfg(feature = "dim2")]
+                    let gcross1 = ii1.transform_vector(SimdFloat::splat(1.0));
+                    #[cfg(feature = "dim2")]
+                    let gcross2 = ii2.transform_vector(SimdFloat::splat(-1.0));
+                    #[cfg(feature = "dim2")]
+                    let rhs = angvel1 - angvel2;
+
+                    #[cfg(feature = "dim3")]
+                    let gcross1 = ii1.transform_vector(tangents1[j]);
+                    #[cfg(feature = "dim3")]
+                    let gcross2 = ii2.transform_vector(-tangents1[j]);
+                    #[cfg(feature = "dim3")]
+                    let rhs = (angvel1 - angvel2).gdot(tangents1[j]);
+
+                    let r = SimdFloat::splat(1.0) / (gcross1.gdot(gcross1) + gcross2.gdot(gcross2));
+
+                    constraint.elements[k].rolling_parts[j] = WVelocityConstraintElementPart {
+                        gcross1,
+                        gcross2,
+                        rhs,
+                        rhs_push: SimdFloat::zero(),
+                        impulse: rolling_impulse * warmstart_coeff,
+                        impulse_push: SimdFloat::zero(),
                         r,
                     };
                 }
@@ -246,6 +447,17 @@ impl WVelocityConstraint {
                 mj_lambda2.linear += tangents1[j] * (-self.im2 * elt.impulse);
                 mj_lambda2.angular += elt.gcross2 * elt.impulse;
             }
+
+            // Spinning/rolling friction only ever touch angular velocity.
+            let spinning_elt = &self.elements[i].spinning_part;
+            mj_lambda1.angular += spinning_elt.gcross1 * spinning_elt.impulse;
+            mj_lambda2.angular += spinning_elt.gcross2 * spinning_elt.impulse;
+
+            for j in 0..DIM - 1 {
+                let elt = &self.elements[i].rolling_parts[j];
+                mj_lambda1.angular += elt.gcross1 * elt.impulse;
+                mj_lambda2.angular += elt.gcross2 * elt.impulse;
+            }
         }
 
         for ii in 0..SIMD_WIDTH {
@@ -258,7 +470,12 @@ impl WVelocityConstraint {
         }
     }
 
-    pub fn solve(&mut self, mj_lambdas: &mut [DeltaVel<f32>]) {
+    // FIXME: no caller threads a second mj_lambdas_push buffer through the island solver yet.
+    pub fn solve(
+        &mut self,
+        mj_lambdas: &mut [DeltaVel<f32>],
+        mj_lambdas_push: &mut [DeltaVel<f32>],
+    ) {
         let mut mj_lambda1 = DeltaVel {
             linear: Vector::from(
                 array![|ii| mj_lambdas[self.mj_lambda1[ii] as usize].linear; SIMD_WIDTH],
@@ -281,23 +498,91 @@ impl WVelocityConstraint {
         for i in 0..self.num_contacts as usize {
             // FIXME: move this out of the for loop?
             let tangents1 = self.dir1.orthonormal_basis();
-            let normal_elt = &self.elements[i].normal_part;
+            let normal_elt = self.elements[i].normal_part;
+            let limit = self.limit * normal_elt.impulse;
+
+            // 2D has a single tangent direction, so just clamp it like before.
+            #[cfg(feature = "dim2")]
+            {
+                let elt = &mut self.elements[i].tangent_parts[0];
+                let dimpulse = tangents1[0].dot(&mj_lambda1.linear)
+                    + elt.gcross1.gdot(mj_lambda1.angular)
+                    - tangents1[0].dot(&mj_lambda2.linear)
+                    + elt.gcross2.gdot(mj_lambda2.angular)
+                    + elt.rhs;
+                let (new_impulse, dlambda) = solve_bilateral(elt.impulse, elt.r, dimpulse, limit);
+                elt.impulse = new_impulse;
+
+                mj_lambda1.linear += tangents1[0] * (self.im1 * dlambda);
+                mj_lambda1.angular += elt.gcross1 * dlambda;
+                mj_lambda2.linear += tangents1[0] * (-self.im2 * dlambda);
+                mj_lambda2.angular += elt.gcross2 * dlambda;
+            }
+
+            // 3D: clamp the two tangent impulses as a single 2D vector (circular cone, not a box).
+            #[cfg(feature = "dim3")]
+            {
+                let elt0 = self.elements[i].tangent_parts[0];
+                let elt1 = self.elements[i].tangent_parts[1];
+
+                let dimpulse0 = tangents1[0].dot(&mj_lambda1.linear)
+                    + elt0.gcross1.gdot(mj_lambda1.angular)
+                    - tangents1[0].dot(&mj_lambda2.linear)
+                    + elt0.gcross2.gdot(mj_lambda2.angular)
+                    + elt0.rhs;
+                let dimpulse1 = tangents1[1].dot(&mj_lambda1.linear)
+                    + elt1.gcross1.gdot(mj_lambda1.angular)
+                    - tangents1[1].dot(&mj_lambda2.linear)
+                    + elt1.gcross2.gdot(mj_lambda2.angular)
+                    + elt1.rhs;
+
+                let (new_impulse0, new_impulse1, dlambda0, dlambda1) = solve_cone(
+                    elt0.impulse,
+                    elt1.impulse,
+                    elt0.r,
+                    elt1.r,
+                    dimpulse0,
+                    dimpulse1,
+                    limit,
+                );
+
+                self.elements[i].tangent_parts[0].impulse = new_impulse0;
+                self.elements[i].tangent_parts[1].impulse = new_impulse1;
+
+                mj_lambda1.linear +=
+                    tangents1[0] * (self.im1 * dlambda0) + tangents1[1] * (self.im1 * dlambda1);
+                mj_lambda1.angular += elt0.gcross1 * dlambda0 + elt1.gcross1 * dlambda1;
+                mj_lambda2.linear +=
+                    tangents1[0] * (-self.im2 * dlambda0) + tangents1[1] * (-self.im2 * dlambda1);
+                mj_lambda2.angular += elt0.gcross2 * dlambda0 + elt1.gcross2 * dlambda1;
+            }
+        }
+
+        // Solve rolling/spinning friction: same shape as tangential friction, angular-only.
+        for i in 0..self.num_contacts as usize {
+            let normal_impulse = self.elements[i].normal_part.impulse;
+
+            let elt = &mut self.elements[i].spinning_part;
+            let dimpulse = elt.gcross1.gdot(mj_lambda1.angular)
+                + elt.gcross2.gdot(mj_lambda2.angular)
+                + elt.rhs;
+            let limit = self.spinning_limit * normal_impulse;
+            let (new_impulse, dlambda) = solve_bilateral(elt.impulse, elt.r, dimpulse, limit);
+            elt.impulse = new_impulse;
+
+            mj_lambda1.angular += elt.gcross1 * dlambda;
+            mj_lambda2.angular += elt.gcross2 * dlambda;
 
             for j in 0..DIM - 1 {
-                let elt = &mut self.elements[i].tangent_parts[j];
-                let dimpulse = tangents1[j].dot(&mj_lambda1.linear)
-                    + elt.gcross1.gdot(mj_lambda1.angular)
-                    - tangents1[j].dot(&mj_lambda2.linear)
+                let elt = &mut self.elements[i].rolling_parts[j];
+                let dimpulse = elt.gcross1.gdot(mj_lambda1.angular)
                     + elt.gcross2.gdot(mj_lambda2.angular)
                     + elt.rhs;
-                let limit = self.limit * normal_elt.impulse;
-                let new_impulse = (elt.impulse - elt.r * dimpulse).simd_clamp(-limit, limit);
-                let dlambda = new_impulse - elt.impulse;
+                let limit = self.rolling_limit * normal_impulse;
+                let (new_impulse, dlambda) = solve_bilateral(elt.impulse, elt.r, dimpulse, limit);
                 elt.impulse = new_impulse;
 
-                mj_lambda1.linear += tangents1[j] * (self.im1 * dlambda);
                 mj_lambda1.angular += elt.gcross1 * dlambda;
-                mj_lambda2.linear += tangents1[j] * (-self.im2 * dlambda);
                 mj_lambda2.angular += elt.gcross2 * dlambda;
             }
         }
@@ -309,8 +594,7 @@ impl WVelocityConstraint {
                 - self.dir1.dot(&mj_lambda2.linear)
                 + elt.gcross2.gdot(mj_lambda2.angular)
                 + elt.rhs;
-            let new_impulse = (elt.impulse - elt.r * dimpulse).simd_max(SimdFloat::zero());
-            let dlambda = new_impulse - elt.impulse;
+            let (new_impulse, dlambda) = solve_unilateral(elt.impulse, elt.r, dimpulse);
             elt.impulse = new_impulse;
 
             mj_lambda1.linear += self.dir1 * (self.im1 * dlambda);
@@ -327,9 +611,263 @@ impl WVelocityConstraint {
             mj_lambdas[self.mj_lambda2[ii] as usize].linear = mj_lambda2.linear.extract(ii);
             mj_lambdas[self.mj_lambda2[ii] as usize].angular = mj_lambda2.angular.extract(ii);
         }
+
+        // Split-impulse: second accumulated-impulse pass against the pseudo-velocity buffer
+        // only. FIXME: the integrator needs to consume `mj_lambdas_push` for positions only
+        // and discard it each step; it is never warmstarted.
+        if self.use_split_impulse {
+            let mut mj_lambda1_push = DeltaVel {
+                linear: Vector::from(
+                    array![|ii| mj_lambdas_push[self.mj_lambda1[ii] as usize].linear; SIMD_WIDTH],
+                ),
+                angular: AngVector::from(
+                    array![|ii| mj_lambdas_push[self.mj_lambda1[ii] as usize].angular; SIMD_WIDTH],
+                ),
+            };
+
+            let mut mj_lambda2_push = DeltaVel {
+                linear: Vector::from(
+                    array![|ii| mj_lambdas_push[self.mj_lambda2[ii] as usize].linear; SIMD_WIDTH],
+                ),
+                angular: AngVector::from(
+                    array![|ii| mj_lambdas_push[self.mj_lambda2[ii] as usize].angular; SIMD_WIDTH],
+                ),
+            };
+
+            for i in 0..self.num_contacts as usize {
+                let elt = &mut self.elements[i].normal_part;
+                let dimpulse = self.dir1.dot(&mj_lambda1_push.linear)
+                    + elt.gcross1.gdot(mj_lambda1_push.angular)
+                    - self.dir1.dot(&mj_lambda2_push.linear)
+                    + elt.gcross2.gdot(mj_lambda2_push.angular)
+                    + elt.rhs_push;
+                let (new_impulse, dlambda) = solve_unilateral(elt.impulse_push, elt.r, dimpulse);
+                elt.impulse_push = new_impulse;
+
+                mj_lambda1_push.linear += self.dir1 * (self.im1 * dlambda);
+                mj_lambda1_push.angular += elt.gcross1 * dlambda;
+                mj_lambda2_push.linear += self.dir1 * (-self.im2 * dlambda);
+                mj_lambda2_push.angular += elt.gcross2 * dlambda;
+            }
+
+            for ii in 0..SIMD_WIDTH {
+                mj_lambdas_push[self.mj_lambda1[ii] as usize].linear =
+                    mj_lambda1_push.linear.extract(ii);
+                mj_lambdas_push[self.mj_lambda1[ii] as usize].angular =
+                    mj_lambda1_push.angular.extract(ii);
+            }
+            for ii in 0..SIMD_WIDTH {
+                mj_lambdas_push[self.mj_lambda2[ii] as usize].linear =
+                    mj_lambda2_push.linear.extract(ii);
+                mj_lambdas_push[self.mj_lambda2[ii] as usize].angular =
+                    mj_lambda2_push.angular.extract(ii);
+            }
+        }
     }
 
-    pub fn writeback_impulses(&self, manifolds_all: &mut [&mut ContactManifold]) {
+    // Jacobi counterpart to `solve`: reads `mj_lambdas` as of iteration start, never mutates
+    // it, and adds its relaxed delta into `mj_lambdas_accum` instead. The caller applies
+    // `mj_lambdas_accum` to `mj_lambdas` once all constraints in the iteration have run.
+    // FIXME: does not apply split-impulse (`rhs_push`/`impulse_push`); see `solve`'s comment.
+    pub fn solve_jacobi(
+        &mut self,
+        mj_lambdas: &[DeltaVel<f32>],
+        mj_lambdas_accum: &mut [DeltaVel<f32>],
+    ) {
+        // Guard the gap noted above: silently skipping split-impulse here would leave
+        // penetration recovery looking enabled but doing nothing.
+        debug_assert!(
+            !self.use_split_impulse,
+            "solve_jacobi does not apply split-impulse position correction; use `solve` instead"
+        );
+
+        let mj_lambda1_start = DeltaVel {
+            linear: Vector::from(
+                array![|ii| mj_lambdas[self.mj_lambda1[ii] as usize].linear; SIMD_WIDTH],
+            ),
+            angular: AngVector::from(
+                array![|ii| mj_lambdas[self.mj_lambda1[ii] as usize].angular; SIMD_WIDTH],
+            ),
+        };
+        let mj_lambda2_start = DeltaVel {
+            linear: Vector::from(
+                array![|ii| mj_lambdas[self.mj_lambda2[ii] as usize].linear; SIMD_WIDTH],
+            ),
+            angular: AngVector::from(
+                array![|ii| mj_lambdas[self.mj_lambda2[ii] as usize].angular; SIMD_WIDTH],
+            ),
+        };
+
+        // `mj_lambda{1,2}` track this constraint's own running velocity (points still chain);
+        // `delta{1,2}` accumulates only its contribution, relaxed and added to the shared
+        // accumulator below.
+        let mut mj_lambda1 = mj_lambda1_start;
+        let mut mj_lambda2 = mj_lambda2_start;
+        let mut delta1 = DeltaVel {
+            linear: Vector::zeros(),
+            angular: AngVector::zero(),
+        };
+        let mut delta2 = DeltaVel {
+            linear: Vector::zeros(),
+            angular: AngVector::zero(),
+        };
+
+        // Solve friction first.
+        for i in 0..self.num_contacts as usize {
+            let tangents1 = self.dir1.orthonormal_basis();
+            let normal_elt = self.elements[i].normal_part;
+            let limit = self.limit * normal_elt.impulse;
+
+            #[cfg(feature = "dim2")]
+            {
+                let elt = &mut self.elements[i].tangent_parts[0];
+                let dimpulse = tangents1[0].dot(&mj_lambda1.linear)
+                    + elt.gcross1.gdot(mj_lambda1.angular)
+                    - tangents1[0].dot(&mj_lambda2.linear)
+                    + elt.gcross2.gdot(mj_lambda2.angular)
+                    + elt.rhs;
+                let (new_impulse, dlambda) = solve_bilateral(elt.impulse, elt.r, dimpulse, limit);
+                elt.impulse = new_impulse;
+
+                mj_lambda1.linear += tangents1[0] * (self.im1 * dlambda);
+                mj_lambda1.angular += elt.gcross1 * dlambda;
+                mj_lambda2.linear += tangents1[0] * (-self.im2 * dlambda);
+                mj_lambda2.angular += elt.gcross2 * dlambda;
+
+                delta1.linear += tangents1[0] * (self.im1 * dlambda);
+                delta1.angular += elt.gcross1 * dlambda;
+                delta2.linear += tangents1[0] * (-self.im2 * dlambda);
+                delta2.angular += elt.gcross2 * dlambda;
+            }
+
+            #[cfg(feature = "dim3")]
+            {
+                let elt0 = self.elements[i].tangent_parts[0];
+                let elt1 = self.elements[i].tangent_parts[1];
+
+                let dimpulse0 = tangents1[0].dot(&mj_lambda1.linear)
+                    + elt0.gcross1.gdot(mj_lambda1.angular)
+                    - tangents1[0].dot(&mj_lambda2.linear)
+                    + elt0.gcross2.gdot(mj_lambda2.angular)
+                    + elt0.rhs;
+                let dimpulse1 = tangents1[1].dot(&mj_lambda1.linear)
+                    + elt1.gcross1.gdot(mj_lambda1.angular)
+                    - tangents1[1].dot(&mj_lambda2.linear)
+                    + elt1.gcross2.gdot(mj_lambda2.angular)
+                    + elt1.rhs;
+
+                let (new_impulse0, new_impulse1, dlambda0, dlambda1) = solve_cone(
+                    elt0.impulse,
+                    elt1.impulse,
+                    elt0.r,
+                    elt1.r,
+                    dimpulse0,
+                    dimpulse1,
+                    limit,
+                );
+
+                self.elements[i].tangent_parts[0].impulse = new_impulse0;
+                self.elements[i].tangent_parts[1].impulse = new_impulse1;
+
+                mj_lambda1.linear +=
+                    tangents1[0] * (self.im1 * dlambda0) + tangents1[1] * (self.im1 * dlambda1);
+                mj_lambda1.angular += elt0.gcross1 * dlambda0 + elt1.gcross1 * dlambda1;
+                mj_lambda2.linear +=
+                    tangents1[0] * (-self.im2 * dlambda0) + tangents1[1] * (-self.im2 * dlambda1);
+                mj_lambda2.angular += elt0.gcross2 * dlambda0 + elt1.gcross2 * dlambda1;
+
+                delta1.linear +=
+                    tangents1[0] * (self.im1 * dlambda0) + tangents1[1] * (self.im1 * dlambda1);
+                delta1.angular += elt0.gcross1 * dlambda0 + elt1.gcross1 * dlambda1;
+                delta2.linear +=
+                    tangents1[0] * (-self.im2 * dlambda0) + tangents1[1] * (-self.im2 * dlambda1);
+                delta2.angular += elt0.gcross2 * dlambda0 + elt1.gcross2 * dlambda1;
+            }
+        }
+
+        // Solve rolling/spinning friction: angular-only, same shape as tangential friction.
+        for i in 0..self.num_contacts as usize {
+            let normal_impulse = self.elements[i].normal_part.impulse;
+
+            let elt = &mut self.elements[i].spinning_part;
+            let dimpulse = elt.gcross1.gdot(mj_lambda1.angular)
+                + elt.gcross2.gdot(mj_lambda2.angular)
+                + elt.rhs;
+            let limit = self.spinning_limit * normal_impulse;
+            let (new_impulse, dlambda) = solve_bilateral(elt.impulse, elt.r, dimpulse, limit);
+            elt.impulse = new_impulse;
+
+            mj_lambda1.angular += elt.gcross1 * dlambda;
+            mj_lambda2.angular += elt.gcross2 * dlambda;
+            delta1.angular += elt.gcross1 * dlambda;
+            delta2.angular += elt.gcross2 * dlambda;
+
+            for j in 0..DIM - 1 {
+                let elt = &mut self.elements[i].rolling_parts[j];
+                let dimpulse = elt.gcross1.gdot(mj_lambda1.angular)
+                    + elt.gcross2.gdot(mj_lambda2.angular)
+                    + elt.rhs;
+                let limit = self.rolling_limit * normal_impulse;
+                let (new_impulse, dlambda) = solve_bilateral(elt.impulse, elt.r, dimpulse, limit);
+                elt.impulse = new_impulse;
+
+                mj_lambda1.angular += elt.gcross1 * dlambda;
+                mj_lambda2.angular += elt.gcross2 * dlambda;
+                delta1.angular += elt.gcross1 * dlambda;
+                delta2.angular += elt.gcross2 * dlambda;
+            }
+        }
+
+        // Solve non-penetration after friction.
+        for i in 0..self.num_contacts as usize {
+            let elt = &mut self.elements[i].normal_part;
+            let dimpulse = self.dir1.dot(&mj_lambda1.linear) + elt.gcross1.gdot(mj_lambda1.angular)
+                - self.dir1.dot(&mj_lambda2.linear)
+                + elt.gcross2.gdot(mj_lambda2.angular)
+                + elt.rhs;
+            let (new_impulse, dlambda) = solve_unilateral(elt.impulse, elt.r, dimpulse);
+            elt.impulse = new_impulse;
+
+            mj_lambda1.linear += self.dir1 * (self.im1 * dlambda);
+            mj_lambda1.angular += elt.gcross1 * dlambda;
+            mj_lambda2.linear += self.dir1 * (-self.im2 * dlambda);
+            mj_lambda2.angular += elt.gcross2 * dlambda;
+
+            delta1.linear += self.dir1 * (self.im1 * dlambda);
+            delta1.angular += elt.gcross1 * dlambda;
+            delta2.linear += self.dir1 * (-self.im2 * dlambda);
+            delta2.angular += elt.gcross2 * dlambda;
+        }
+
+        // Relax by the per-body constraint count and accumulate (never overwrite).
+        let relaxed1 = DeltaVel {
+            linear: delta1.linear * self.inv_lambda1_count,
+            angular: delta1.angular * self.inv_lambda1_count,
+        };
+        let relaxed2 = DeltaVel {
+            linear: delta2.linear * self.inv_lambda2_count,
+            angular: delta2.angular * self.inv_lambda2_count,
+        };
+
+        for ii in 0..SIMD_WIDTH {
+            mj_lambdas_accum[self.mj_lambda1[ii] as usize].linear += relaxed1.linear.extract(ii);
+            mj_lambdas_accum[self.mj_lambda1[ii] as usize].angular += relaxed1.angular.extract(ii);
+        }
+        for ii in 0..SIMD_WIDTH {
+            mj_lambdas_accum[self.mj_lambda2[ii] as usize].linear += relaxed2.linear.extract(ii);
+            mj_lambdas_accum[self.mj_lambda2[ii] as usize].angular += relaxed2.angular.extract(ii);
+        }
+    }
+
+    // FIXME: no caller passes a real `contact_callback` yet, so `ContactSolverCallback` is
+    // unreachable until the island solver is updated to pass one through.
+    pub fn writeback_impulses(
+        &self,
+        manifolds_all: &mut [&mut ContactManifold],
+        contact_callback: Option<&dyn ContactSolverCallback>,
+    ) {
+        let tangents1 = self.dir1.orthonormal_basis();
+
         for k in 0..self.num_contacts as usize {
             let impulses: [_; SIMD_WIDTH] = self.elements[k].normal_part.impulse.into();
             let tangent_impulses: [_; SIMD_WIDTH] =
@@ -337,21 +875,57 @@ impl WVelocityConstraint {
             #[cfg(feature = "dim3")]
             let bitangent_impulses: [_; SIMD_WIDTH] =
                 self.elements[k].tangent_parts[1].impulse.into();
+            let spinning_impulses: [_; SIMD_WIDTH] = self.elements[k].spinning_part.impulse.into();
+            let rolling_impulses: [_; SIMD_WIDTH] =
+                self.elements[k].rolling_parts[0].impulse.into();
+            #[cfg(feature = "dim3")]
+            let bi_rolling_impulses: [_; SIMD_WIDTH] =
+                self.elements[k].rolling_parts[1].impulse.into();
 
             for ii in 0..SIMD_WIDTH {
                 let manifold = &mut manifolds_all[self.manifold_id[ii]];
                 let k_base = self.manifold_contact_id;
                 let active_contacts = manifold.active_contacts_mut();
                 active_contacts[k_base + k].impulse = impulses[ii];
+                active_contacts[k_base + k].spinning_impulse = spinning_impulses[ii];
 
                 #[cfg(feature = "dim2")]
                 {
                     active_contacts[k_base + k].tangent_impulse = tangent_impulses[ii];
+                    active_contacts[k_base + k].rolling_impulse = rolling_impulses[ii];
                 }
                 #[cfg(feature = "dim3")]
                 {
                     active_contacts[k_base + k].tangent_impulse =
                         [tangent_impulses[ii], bitangent_impulses[ii]];
+                    active_contacts[k_base + k].rolling_impulse =
+                        [rolling_impulses[ii], bi_rolling_impulses[ii]];
+                }
+            }
+
+            // Padding lanes repeat a real manifold; only report the first `num_active_lanes`.
+            if let Some(callback) = contact_callback {
+                for ii in 0..self.num_active_lanes as usize {
+                    let normal = self.dir1.extract(ii);
+                    #[cfg(feature = "dim2")]
+                    let tangent_impulse = [tangent_impulses[ii]];
+                    #[cfg(feature = "dim3")]
+                    let tangent_impulse = [tangent_impulses[ii], bitangent_impulses[ii]];
+
+                    let mut total_impulse = normal * impulses[ii];
+                    for j in 0..DIM - 1 {
+                        total_impulse += tangents1[j].extract(ii) * tangent_impulse[j];
+                    }
+
+                    let src_manifold = &manifolds_all[self.manifold_id[ii]];
+                    callback.solved_contact(&SolvedContact {
+                        body1: src_manifold.body_pair.body1,
+                        body2: src_manifold.body_pair.body2,
+                        contact_point: Point::from(self.elements[k].point.coords.extract(ii)),
+                        normal_impulse: impulses[ii],
+                        tangent_impulse,
+                        total_impulse,
+                    });
                 }
             }
         }